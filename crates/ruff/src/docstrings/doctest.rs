@@ -0,0 +1,204 @@
+use rustpython_parser::ast::Suite;
+use rustpython_parser::Parse;
+
+use ruff_text_size::TextSize;
+
+/// A snippet of Python source recovered from a docstring, along with enough information to map
+/// any offset within it back to the corresponding offset in the original source file.
+///
+/// A diagnostic raised while checking [`source`](Self::source) is reported at
+/// [`to_original_offset`](Self::to_original_offset), so that it points at the real location in
+/// the source file rather than at the synthetic, re-parsed snippet. A single scalar offset isn't
+/// enough for this: once prompts and indentation are stripped, the byte distance between a line
+/// of `source` and its counterpart in the docstring varies from line to line (every stripped
+/// `>>> `/`... ` prompt and its indentation add to the distance for all the lines after it),
+/// so each line needs its own mapping.
+#[derive(Debug)]
+pub(crate) struct DoctestSnippet {
+    pub(crate) source: String,
+    /// Parallel to the lines of `source`: `lines[i]` is `(start of line i in source, start of
+    /// line i in the original file)`.
+    lines: Vec<(TextSize, TextSize)>,
+}
+
+impl DoctestSnippet {
+    /// Map a byte offset within [`source`](Self::source) back to the corresponding offset in
+    /// the original source file.
+    pub(crate) fn to_original_offset(&self, source_offset: TextSize) -> TextSize {
+        let line_index = self
+            .lines
+            .partition_point(|(line_start, _)| *line_start <= source_offset)
+            .saturating_sub(1);
+        let (line_start, original_start) = self.lines[line_index];
+        original_start + (source_offset - line_start)
+    }
+}
+
+/// Accumulates a [`DoctestSnippet`] one line at a time, recording where each line of the
+/// synthetic `source` maps back to in the original file.
+#[derive(Default)]
+struct SnippetBuilder {
+    source: String,
+    lines: Vec<(TextSize, TextSize)>,
+}
+
+impl SnippetBuilder {
+    /// Append `code` as the next line of the snippet; `original_offset` is where `code` starts
+    /// in the original file.
+    fn push_line(&mut self, code: &str, original_offset: TextSize) {
+        let source_offset = TextSize::try_from(self.source.len()).unwrap();
+        self.lines.push((source_offset, original_offset));
+        self.source.push_str(code);
+        self.source.push('\n');
+    }
+
+    fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    fn finish(self) -> DoctestSnippet {
+        DoctestSnippet {
+            source: self.source,
+            lines: self.lines,
+        }
+    }
+}
+
+/// Recover every `>>>`/`...` doctest block and every fenced ` ```python ` block embedded in a
+/// docstring body, and parse each one into its own [`Suite`].
+///
+/// `body` is the docstring's contents with the surrounding quotes already stripped; `body_start`
+/// is that body's offset within the source file. A snippet that fails to parse is silently
+/// dropped rather than reported as a syntax error: docstrings routinely contain
+/// deliberately-incomplete or illustrative code that was never meant to be valid Python on its
+/// own.
+pub(crate) fn doctests(body: &str, body_start: TextSize) -> Vec<(Suite, DoctestSnippet)> {
+    extract_interactive_blocks(body, body_start)
+        .into_iter()
+        .chain(extract_fenced_blocks(body, body_start))
+        .filter_map(|snippet| {
+            Suite::parse(&snippet.source, "<doctest>")
+                .ok()
+                .map(|suite| (suite, snippet))
+        })
+        .collect()
+}
+
+/// Extract `>>>`/`...` interactive blocks, stripping the prompts, the indentation relative to
+/// the docstring, and any expected-output lines that follow (they're neither a prompt nor a
+/// continuation, so they end the block without being included).
+fn extract_interactive_blocks(body: &str, body_start: TextSize) -> Vec<DoctestSnippet> {
+    let mut snippets = Vec::new();
+    let mut lines = line_offsets(body).into_iter().peekable();
+
+    while let Some((line, line_offset)) = lines.next() {
+        let Some(indent_len) = line.find(">>> ") else {
+            continue;
+        };
+        if !line[..indent_len].trim().is_empty() {
+            continue;
+        }
+
+        let mut builder = SnippetBuilder::default();
+        push_prompt_line(&mut builder, line, indent_len, body_start + line_offset);
+
+        while let Some(&(next_line, next_offset)) = lines.peek() {
+            let has_indent =
+                next_line.len() >= indent_len && next_line[..indent_len].trim().is_empty();
+            if !has_indent || !next_line[indent_len..].starts_with("... ") {
+                break;
+            }
+            push_prompt_line(&mut builder, next_line, indent_len, body_start + next_offset);
+            lines.next();
+        }
+
+        snippets.push(builder.finish());
+    }
+
+    snippets
+}
+
+/// Extract fenced ` ```python ... ``` ` blocks verbatim (minus the fences themselves).
+fn extract_fenced_blocks(body: &str, body_start: TextSize) -> Vec<DoctestSnippet> {
+    let mut snippets = Vec::new();
+    let mut lines = line_offsets(body).into_iter();
+
+    while let Some((line, _)) = lines.next() {
+        if line.trim() != "```python" {
+            continue;
+        }
+
+        let mut builder = SnippetBuilder::default();
+        for (line, line_offset) in lines.by_ref() {
+            if line.trim() == "```" {
+                break;
+            }
+            builder.push_line(line, body_start + line_offset);
+        }
+
+        if !builder.is_empty() {
+            snippets.push(builder.finish());
+        }
+    }
+
+    snippets
+}
+
+/// Strip a `>>> `/`... ` prompt (and its indentation) from `line` and append the remaining code
+/// to `builder`, recording that it started at `original_offset` in the source file.
+fn push_prompt_line(builder: &mut SnippetBuilder, line: &str, indent_len: usize, original_offset: TextSize) {
+    let code = &line[indent_len + 4..];
+    let code_offset = original_offset + TextSize::try_from(indent_len + 4).unwrap();
+    builder.push_line(code, code_offset);
+}
+
+/// Return each line of `text` paired with its byte offset from the start of `text`.
+fn line_offsets(text: &str) -> Vec<(&str, TextSize)> {
+    let mut offset = TextSize::from(0);
+    let mut lines = Vec::new();
+    for line in text.split('\n') {
+        lines.push((line, offset));
+        offset += TextSize::try_from(line.len() + 1).unwrap();
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use ruff_text_size::TextSize;
+
+    use super::extract_interactive_blocks;
+
+    #[test]
+    fn continuation_line_maps_to_its_own_offset() {
+        let body = "    >>> x = 1\n    ... y = 2\n";
+        let snippets = extract_interactive_blocks(body, TextSize::from(0));
+        let [snippet] = snippets.as_slice() else {
+            panic!("expected a single doctest snippet, got {snippets:?}");
+        };
+        assert_eq!(snippet.source, "x = 1\ny = 2\n");
+
+        // The first line's code (`x = 1`) starts right after `    >>> `.
+        assert_eq!(snippet.to_original_offset(TextSize::from(0)), TextSize::from(8));
+        // The second line's code (`y = 2`) starts after the *second* `    ... ` prompt, not at
+        // `x`'s offset plus the length of one line of `source`.
+        assert_eq!(snippet.to_original_offset(TextSize::from(6)), TextSize::from(22));
+        assert_eq!(snippet.to_original_offset(TextSize::from(8)), TextSize::from(24));
+    }
+
+    #[test]
+    fn expected_output_line_ends_the_block() {
+        let body = ">>> 1 + 1\n2\n";
+        let snippets = extract_interactive_blocks(body, TextSize::from(0));
+        let [snippet] = snippets.as_slice() else {
+            panic!("expected a single doctest snippet, got {snippets:?}");
+        };
+        assert_eq!(snippet.source, "1 + 1\n");
+    }
+
+    #[test]
+    fn unparsable_snippet_is_silently_dropped() {
+        let body = ">>> def f(:\n...     pass\n";
+        assert_eq!(super::doctests(body, TextSize::from(0)).len(), 0);
+    }
+}
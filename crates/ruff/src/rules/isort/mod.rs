@@ -0,0 +1,3 @@
+pub(crate) mod rules;
+pub mod settings;
+pub(crate) mod sorting;
@@ -0,0 +1,3 @@
+pub(crate) use unsorted_imports::*;
+
+mod unsorted_imports;
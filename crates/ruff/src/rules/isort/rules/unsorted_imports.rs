@@ -0,0 +1,73 @@
+use rustpython_parser::ast::{self, Ranged, Stmt};
+
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks that a contiguous run of `import`/`from ... import` statements is sorted by module
+/// name.
+///
+/// ## Why is this bad?
+/// A consistent import order makes it easy to tell, at a glance, whether a module is already
+/// imported, and avoids merge conflicts from appending to the end of the block.
+///
+/// By default, module names are compared byte-for-byte (`module16` sorts before `module8`); set
+/// `isort.sorting` to `Sorting::Natural` to instead sort numbered module names the way a reader
+/// would expect (`module8` before `module16`).
+///
+/// ## Example
+/// ```python
+/// import sys
+/// import os
+/// ```
+///
+/// Use instead:
+/// ```python
+/// import os
+/// import sys
+/// ```
+#[violation]
+pub struct UnsortedImports;
+
+impl Violation for UnsortedImports {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        "Import block is un-sorted".to_string()
+    }
+}
+
+/// I001
+pub(crate) fn unsorted_imports(checker: &mut Checker, body: &[Stmt]) {
+    let sorting = checker.settings.isort.sorting;
+
+    // Check each maximal run of consecutive import statements independently: a non-import
+    // statement breaks up the block, and the rule has nothing to say about ordering across it.
+    for block in body.split(|stmt| module_name(stmt).is_none()) {
+        let names = block.iter().filter_map(module_name).collect::<Vec<_>>();
+        let is_sorted = names
+            .windows(2)
+            .all(|pair| sorting.compare(pair[0], pair[1]).is_le());
+
+        if !is_sorted {
+            if let (Some(first), Some(last)) = (block.first(), block.last()) {
+                checker.diagnostics.push(Diagnostic::new(
+                    UnsortedImports,
+                    first.range().cover(last.range()),
+                ));
+            }
+        }
+    }
+}
+
+/// The name this statement should be sorted by, or `None` if it isn't an import statement at
+/// all (and so ends the current block). A relative `from . import ...` has no module name, so
+/// it also yields `None` and ends the block.
+fn module_name(stmt: &Stmt) -> Option<&str> {
+    match stmt {
+        Stmt::Import(ast::StmtImport { names, .. }) => names.first().map(|alias| alias.name.as_str()),
+        Stmt::ImportFrom(ast::StmtImportFrom { module, .. }) => module.as_deref(),
+        _ => None,
+    }
+}
@@ -0,0 +1,13 @@
+use super::sorting::Sorting;
+
+/// Settings for isort's import-sorting rules, and for any other rule (e.g.
+/// `unsorted-dunder-all`) that sorts names the same way isort does.
+#[derive(Debug, Clone, Default)]
+pub struct Settings {
+    /// The comparator used to decide whether two names are already in sorted order.
+    ///
+    /// Defaults to [`Sorting::Lexicographic`], matching isort's historical (and Python's
+    /// built-in `str`) ordering; set to [`Sorting::Natural`] to sort numbered names like
+    /// `item8`/`item16` the way a reader would expect instead of byte-for-byte.
+    pub sorting: Sorting,
+}
@@ -0,0 +1,149 @@
+use std::cmp::Ordering;
+
+/// The comparator used to order two names against each other, shared by isort's import sorting
+/// and the `__all__`-sorting rule.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Sorting {
+    /// Pure byte-wise ordering (Python's default `str` comparison). `item16` sorts before
+    /// `item8`, since `'1' < '8'`.
+    #[default]
+    Lexicographic,
+    /// "Natural"/version ordering, as used by the Rust style guide's path-sorting rules:
+    /// `item8` sorts before `item16`, and `v2` before `v10`.
+    Natural,
+}
+
+impl Sorting {
+    pub(crate) fn compare(self, a: &str, b: &str) -> Ordering {
+        match self {
+            Sorting::Lexicographic => a.cmp(b),
+            Sorting::Natural => natural_cmp(a, b),
+        }
+    }
+}
+
+/// Compare `a` and `b` using natural (a.k.a. version) ordering: split each string into maximal
+/// runs of digits and non-digits, then compare corresponding runs pairwise, treating digit runs
+/// as numbers (ignoring leading zeros) rather than as strings.
+///
+/// This is the scheme used by the Rust style guide for sorting paths, and mirrors what a reader
+/// expects when scanning a numbered sequence: `item8 < item16 < item100`, rather than the
+/// lexicographic `item100 < item16 < item8`.
+fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let mut a_runs = Runs::new(a);
+    let mut b_runs = Runs::new(b);
+
+    loop {
+        return match (a_runs.next(), b_runs.next()) {
+            (Some(Run::Digits(a)), Some(Run::Digits(b))) => match compare_digit_runs(a, b) {
+                Ordering::Equal => continue,
+                ordering => ordering,
+            },
+            (Some(Run::Other(a)), Some(Run::Other(b))) => match a.cmp(b) {
+                Ordering::Equal => continue,
+                ordering => ordering,
+            },
+            // A digit run and a non-digit run never compare equal; order non-digits first so
+            // that, e.g., `"-"` sorts before `"1"` when the runs misalign.
+            (Some(Run::Digits(_)), Some(Run::Other(_))) => Ordering::Greater,
+            (Some(Run::Other(_)), Some(Run::Digits(_))) => Ordering::Less,
+            (None, None) => Ordering::Equal,
+            (None, Some(_)) => Ordering::Less,
+            (Some(_), None) => Ordering::Greater,
+        };
+    }
+}
+
+/// Compare two runs of decimal digits by numeric value, ignoring leading zeros; on a numeric
+/// tie (e.g. `"01"` vs. `"1"`), the shorter (less-padded) run sorts first.
+fn compare_digit_runs(a: &str, b: &str) -> Ordering {
+    let a_trimmed = a.trim_start_matches('0');
+    let b_trimmed = b.trim_start_matches('0');
+    a_trimmed
+        .len()
+        .cmp(&b_trimmed.len())
+        .then_with(|| a_trimmed.cmp(b_trimmed))
+        .then_with(|| a.len().cmp(&b.len()))
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Run<'a> {
+    Digits(&'a str),
+    Other(&'a str),
+}
+
+/// An iterator over the maximal runs of digits and non-digits that make up a string.
+struct Runs<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Runs<'a> {
+    fn new(s: &'a str) -> Self {
+        Self { rest: s }
+    }
+}
+
+impl<'a> Iterator for Runs<'a> {
+    type Item = Run<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut chars = self.rest.char_indices();
+        let (_, first) = chars.next()?;
+        let is_digit = first.is_ascii_digit();
+
+        let end = chars
+            .find(|(_, c)| c.is_ascii_digit() != is_digit)
+            .map_or(self.rest.len(), |(i, _)| i);
+
+        let (run, rest) = self.rest.split_at(end);
+        self.rest = rest;
+
+        Some(if is_digit {
+            Run::Digits(run)
+        } else {
+            Run::Other(run)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use super::Sorting;
+
+    #[test]
+    fn natural_orders_numbered_names_by_value() {
+        assert_eq!(Sorting::Natural.compare("item8", "item16"), Ordering::Less);
+        assert_eq!(Sorting::Natural.compare("v2", "v10"), Ordering::Less);
+        assert_eq!(Sorting::Natural.compare("item16", "item8"), Ordering::Greater);
+    }
+
+    #[test]
+    fn lexicographic_orders_numbered_names_by_byte() {
+        // The default: `item16` sorts before `item8`, since `'1' < '8'`.
+        assert_eq!(
+            Sorting::Lexicographic.compare("item8", "item16"),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn natural_ignores_leading_zeros_when_comparing_value() {
+        // "007" is numerically 7, which is less than 8, regardless of the padding.
+        assert_eq!(Sorting::Natural.compare("item007", "item8"), Ordering::Less);
+    }
+
+    #[test]
+    fn natural_breaks_numeric_ties_by_shorter_string_first() {
+        // "01" and "1" have the same numeric value; the less-padded one sorts first.
+        assert_eq!(Sorting::Natural.compare("item01", "item1"), Ordering::Greater);
+        assert_eq!(Sorting::Natural.compare("item1", "item01"), Ordering::Less);
+    }
+
+    #[test]
+    fn natural_falls_back_to_byte_order_for_non_digit_runs() {
+        assert_eq!(Sorting::Natural.compare("alpha", "beta"), Ordering::Less);
+        assert_eq!(Sorting::Natural.compare("item", "item8"), Ordering::Less);
+    }
+}
@@ -0,0 +1,159 @@
+use num_bigint::BigInt;
+use rustpython_parser::ast::{self, Constant, Expr};
+
+/// The statically-known truthiness of an expression, as far as it can be determined without
+/// executing it.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum Kind {
+    /// The expression is always falsy (e.g. `""`, `0`, `None`, `[]`).
+    Empty,
+    /// The expression is always truthy (e.g. `"foo"`, `1`, `(1,)`).
+    NonEmpty,
+    /// The expression's truthiness can't be determined statically (e.g. it's not a literal, or
+    /// it's a literal built from non-constant parts).
+    Unknown,
+}
+
+/// Classify the statically-known truthiness of `expr`.
+///
+/// Handles string, bytes, numeric, and f-string literals, `True`/`False`/`None`, and the
+/// "empty vs. non-empty" container displays (`list`, `tuple`, `set`, `dict`). Anything else
+/// (names, calls, attribute accesses, etc.) is reported as [`Kind::Unknown`], since its
+/// truthiness depends on runtime behavior we can't see here.
+pub(crate) fn constant_truthiness(expr: &Expr) -> Kind {
+    match expr {
+        Expr::Constant(ast::ExprConstant { value, .. }) => match value {
+            Constant::Str(value) => is_empty_to_kind(value.is_empty()),
+            Constant::Bytes(value) => is_empty_to_kind(value.is_empty()),
+            Constant::Bool(value) => is_empty_to_kind(!value),
+            Constant::None => Kind::Empty,
+            Constant::Int(value) => is_empty_to_kind(value == &BigInt::from(0)),
+            Constant::Float(value) => is_empty_to_kind(*value == 0.0),
+            Constant::Complex { real, imag } => is_empty_to_kind(*real == 0.0 && *imag == 0.0),
+            _ => Kind::Unknown,
+        },
+        Expr::JoinedStr(ast::ExprJoinedStr { values, range: _ }) => {
+            // An f-string is empty only if every part is a known-empty constant, and non-empty
+            // as soon as one part is a known-non-empty constant; a non-constant interpolation
+            // (e.g. `f"{x}"`) makes the whole thing unknown unless some other part (before or
+            // after it) proves it non-empty.
+            let mut saw_unknown = false;
+            for value in values {
+                match value {
+                    Expr::Constant(ast::ExprConstant { value, .. }) => match value {
+                        Constant::Str(value) if !value.is_empty() => return Kind::NonEmpty,
+                        Constant::Bytes(value) if !value.is_empty() => return Kind::NonEmpty,
+                        _ => {}
+                    },
+                    _ => saw_unknown = true,
+                }
+            }
+            if saw_unknown {
+                Kind::Unknown
+            } else {
+                Kind::Empty
+            }
+        }
+        Expr::List(ast::ExprList { elts, .. }) | Expr::Set(ast::ExprSet { elts, .. }) => {
+            is_empty_to_kind(elts.is_empty())
+        }
+        // A `**`-spread entry is represented as a `None` key; its contribution to emptiness
+        // depends on the spread value at runtime, so any dict containing one is `Unknown`.
+        Expr::Dict(ast::ExprDict { keys, .. }) => {
+            if keys.iter().any(Option::is_none) {
+                Kind::Unknown
+            } else {
+                is_empty_to_kind(keys.is_empty())
+            }
+        }
+        // A tuple display is truthy as soon as it has one element, regardless of what that
+        // element is (including a non-literal, e.g. `(x,)`).
+        Expr::Tuple(ast::ExprTuple { elts, .. }) => is_empty_to_kind(elts.is_empty()),
+        _ => Kind::Unknown,
+    }
+}
+
+fn is_empty_to_kind(is_empty: bool) -> Kind {
+    if is_empty {
+        Kind::Empty
+    } else {
+        Kind::NonEmpty
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rustpython_parser::ast::Expr;
+    use rustpython_parser::Parse;
+
+    use super::{constant_truthiness, Kind};
+
+    fn truthiness(source: &str) -> Kind {
+        let expr = Expr::parse(source, "<test>").unwrap();
+        constant_truthiness(&expr)
+    }
+
+    #[test]
+    fn string_and_bytes_literals() {
+        assert_eq!(truthiness("\"\""), Kind::Empty);
+        assert_eq!(truthiness("\"x\""), Kind::NonEmpty);
+        assert_eq!(truthiness("b\"\""), Kind::Empty);
+        assert_eq!(truthiness("b\"x\""), Kind::NonEmpty);
+    }
+
+    #[test]
+    fn numeric_and_singleton_literals() {
+        assert_eq!(truthiness("0"), Kind::Empty);
+        assert_eq!(truthiness("1"), Kind::NonEmpty);
+        assert_eq!(truthiness("0.0"), Kind::Empty);
+        assert_eq!(truthiness("1.5"), Kind::NonEmpty);
+        assert_eq!(truthiness("True"), Kind::NonEmpty);
+        assert_eq!(truthiness("False"), Kind::Empty);
+        assert_eq!(truthiness("None"), Kind::Empty);
+    }
+
+    #[test]
+    fn container_displays() {
+        assert_eq!(truthiness("[]"), Kind::Empty);
+        assert_eq!(truthiness("[1]"), Kind::NonEmpty);
+        assert_eq!(truthiness("{}"), Kind::Empty);
+        assert_eq!(truthiness("{1: 2}"), Kind::NonEmpty);
+        assert_eq!(truthiness("()"), Kind::Empty);
+    }
+
+    #[test]
+    fn one_element_tuple_is_always_truthy() {
+        // `(x,)` is a non-empty tuple regardless of what `x` is.
+        assert_eq!(truthiness("(x,)"), Kind::NonEmpty);
+        assert_eq!(truthiness("(None,)"), Kind::NonEmpty);
+    }
+
+    #[test]
+    fn dict_unpacking_spread_is_unknown() {
+        // `{**x}` might be empty or not, depending on `x`.
+        assert_eq!(truthiness("{**x}"), Kind::Unknown);
+        assert_eq!(truthiness("{1: 2, **x}"), Kind::Unknown);
+    }
+
+    #[test]
+    fn fstring_with_only_constant_parts() {
+        assert_eq!(truthiness("f\"\""), Kind::Empty);
+        assert_eq!(truthiness("f\"literal\""), Kind::NonEmpty);
+    }
+
+    #[test]
+    fn fstring_with_non_constant_interpolation_is_unknown() {
+        // A non-constant interpolation makes the whole f-string unknown...
+        assert_eq!(truthiness("f\"{x}\""), Kind::Unknown);
+        assert_eq!(truthiness("f\"{x}{y}\""), Kind::Unknown);
+        // ...unless a known-non-empty literal part already proves it's truthy.
+        assert_eq!(truthiness("f\"literal{x}\""), Kind::NonEmpty);
+    }
+
+    #[test]
+    fn parenthesized_non_literal_is_unknown() {
+        assert_eq!(truthiness("(x)"), Kind::Unknown);
+        assert_eq!(truthiness("x"), Kind::Unknown);
+        assert_eq!(truthiness("f()"), Kind::Unknown);
+    }
+}
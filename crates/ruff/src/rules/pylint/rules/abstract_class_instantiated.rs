@@ -1,4 +1,4 @@
-use rustpython_parser::ast::{self, Constant, Expr, Ranged};
+use rustpython_parser::ast::{self, Expr, Ranged};
 
 use ruff_diagnostics::{Diagnostic, Violation};
 use ruff_macros::{derive_message_formats, violation};
@@ -36,7 +36,6 @@ use crate::checkers::ast::Checker;
 ///         print("bhaaaaa")
 ///
 /// sheep = Sheep()
-///     pass
 /// ```
 #[violation]
 pub struct AbstractClassInstantiated {
@@ -53,62 +52,24 @@ impl Violation for AbstractClassInstantiated {
 
 /// PLE0110
 pub(crate) fn abstract_class_instantiated(checker: &mut Checker, expr: &Expr) {
-    match test {
-        Expr::Constant(ast::ExprConstant { value, .. }) => match value {
-            Constant::Str(value, ..) => {
-                checker.diagnostics.push(Diagnostic::new(
-                    AssertOnStringLiteral {
-                        kind: if value.is_empty() {
-                            Kind::Empty
-                        } else {
-                            Kind::NonEmpty
-                        },
-                    },
-                    test.range(),
-                ));
-            }
-            Constant::Bytes(value) => {
-                checker.diagnostics.push(Diagnostic::new(
-                    AssertOnStringLiteral {
-                        kind: if value.is_empty() {
-                            Kind::Empty
-                        } else {
-                            Kind::NonEmpty
-                        },
-                    },
-                    test.range(),
-                ));
-            }
-            _ => {}
-        },
-        Expr::JoinedStr(ast::ExprJoinedStr { values, range: _ }) => {
-            checker.diagnostics.push(Diagnostic::new(
-                AssertOnStringLiteral {
-                    kind: if values.iter().all(|value| match value {
-                        Expr::Constant(ast::ExprConstant { value, .. }) => match value {
-                            Constant::Str(value, ..) => value.is_empty(),
-                            Constant::Bytes(value) => value.is_empty(),
-                            _ => false,
-                        },
-                        _ => false,
-                    }) {
-                        Kind::Empty
-                    } else if values.iter().any(|value| match value {
-                        Expr::Constant(ast::ExprConstant { value, .. }) => match value {
-                            Constant::Str(value, ..) => !value.is_empty(),
-                            Constant::Bytes(value) => !value.is_empty(),
-                            _ => false,
-                        },
-                        _ => false,
-                    }) {
-                        Kind::NonEmpty
-                    } else {
-                        Kind::Unknown
-                    },
-                },
-                test.range(),
-            ));
-        }
-        _ => {}
+    let Expr::Call(ast::ExprCall { func, .. }) = expr else {
+        return;
+    };
+    let Expr::Name(ast::ExprName { id, .. }) = func.as_ref() else {
+        return;
+    };
+
+    let Some(binding_id) = checker.semantic().lookup_symbol(id) else {
+        return;
+    };
+    let Some(class_def) = checker.semantic().binding(binding_id).as_class_def_stmt() else {
+        return;
+    };
+
+    if checker.semantic().is_abstract_class(class_def) {
+        checker.diagnostics.push(Diagnostic::new(
+            AbstractClassInstantiated { name: id.clone() },
+            expr.range(),
+        ));
     }
 }
@@ -0,0 +1,53 @@
+use rustpython_parser::ast::{Expr, Ranged};
+
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+
+use crate::checkers::ast::Checker;
+use crate::rules::pylint::helpers::{constant_truthiness, Kind};
+
+/// ## What it does
+/// Checks for `assert` statements whose test expression has a statically-known truthiness.
+///
+/// ## Why is this bad?
+/// An assertion on a constant (e.g. `assert "always true"`, `assert 0`) either always passes
+/// or always fails, regardless of any runtime condition. If it always passes, the assertion is
+/// dead code, and often a mistake (e.g. `assert (x, "message")`, which is always truthy because
+/// it's a non-empty tuple, not the intended "assert `x`, with this message" semantics); if it
+/// always fails, it's likely a mistake too.
+///
+/// ## Example
+/// ```python
+/// assert "always true"
+/// assert (x, "message")  # always true: this is a non-empty tuple, not `assert x, "message"`
+/// ```
+///
+/// Use instead:
+/// ```python
+/// assert x, "message"
+/// ```
+#[violation]
+pub struct AssertOnStringLiteral {
+    kind: Kind,
+}
+
+impl Violation for AssertOnStringLiteral {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        match self.kind {
+            Kind::Empty => "Assert on an empty constant is always false".to_string(),
+            Kind::NonEmpty => "Assert on a non-empty constant is always true".to_string(),
+            Kind::Unknown => "Assert on a constant value".to_string(),
+        }
+    }
+}
+
+/// PLW0129
+pub(crate) fn assert_on_string_literal(checker: &mut Checker, test: &Expr) {
+    match constant_truthiness(test) {
+        Kind::Unknown => {}
+        kind => checker
+            .diagnostics
+            .push(Diagnostic::new(AssertOnStringLiteral { kind }, test.range())),
+    }
+}
@@ -0,0 +1,279 @@
+use num_bigint::BigInt;
+use rustpython_parser::ast::{self, CmpOp, Constant, Expr, Ranged};
+
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::comparable::ComparableExpr;
+
+use crate::checkers::ast::Checker;
+
+#[derive(Debug, PartialEq, Eq)]
+enum LenCompareKind {
+    /// `len(x) < 0`, `len(x) <= -1`, ... : never true, since `len()` can't be negative.
+    AlwaysFalse,
+    /// `len(x) >= 0`, `len(x) > -1`, ... : always true, since `len()` can't be negative.
+    AlwaysTrue,
+    /// `len(x) <= 0`, `len(x) < 1`: equivalent to the clearer `len(x) == 0` (or `not x`).
+    RedundantBound,
+    /// `len(x) > len(x)`, `len(x) != len(x)`: the two sides are the same expression, so the
+    /// comparison can never be true.
+    SelfCompareAlwaysFalse,
+    /// `len(x) >= len(x)`, `len(x) == len(x)`: the two sides are the same expression, so the
+    /// comparison is always true.
+    SelfCompareAlwaysTrue,
+}
+
+/// ## What it does
+/// Checks for comparisons of a `len(...)` call against a value that can never make the
+/// comparison meaningful, because `len()` is always non-negative.
+///
+/// ## Why is this bad?
+/// `len(x) < 0` and `len(x) >= 0` always evaluate to `False`/`True` respectively, regardless of
+/// `x`, so they're most likely a mistake. `len(x) <= 0` and `len(x) < 1` are not wrong, but are
+/// better expressed as `len(x) == 0` or `not x`, which state the intent directly instead of
+/// relying on a reader to notice that `len()` has a known lower bound. Likewise, comparing
+/// `len(...)` of the same expression to itself (e.g. `len(x) > len(x)`) is always false or
+/// always true, regardless of `x`.
+///
+/// ## Example
+/// ```python
+/// if len(x) < 0:
+///     ...
+/// if len(x) <= 0:
+///     ...
+/// if len(x) > len(x):
+///     ...
+/// ```
+///
+/// Use instead:
+/// ```python
+/// if len(x) == 0:
+///     ...
+/// ```
+#[violation]
+pub struct LenCompareToZero {
+    kind: LenCompareKind,
+}
+
+impl Violation for LenCompareToZero {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        match self.kind {
+            LenCompareKind::AlwaysFalse => {
+                "Comparison of `len(...)` is always false, as `len()` is never negative"
+                    .to_string()
+            }
+            LenCompareKind::AlwaysTrue => {
+                "Comparison of `len(...)` is always true, as `len()` is never negative"
+                    .to_string()
+            }
+            LenCompareKind::RedundantBound => {
+                "`len(...)` bounded above by 0 or 1; prefer `len(...) == 0` (or `not ...`)"
+                    .to_string()
+            }
+            LenCompareKind::SelfCompareAlwaysFalse => {
+                "Comparison of `len(...)` to itself is always false".to_string()
+            }
+            LenCompareKind::SelfCompareAlwaysTrue => {
+                "Comparison of `len(...)` to itself is always true".to_string()
+            }
+        }
+    }
+}
+
+/// PLW0131
+pub(crate) fn len_compare_to_zero(checker: &mut Checker, compare: &ast::ExprCompare) {
+    let ast::ExprCompare {
+        left,
+        ops,
+        comparators,
+        range,
+    } = compare;
+
+    // Bail out on chained comparisons (`a < len(x) < b`): the lint only has something useful to
+    // say about a single, two-operand relation.
+    let [op] = ops.as_slice() else {
+        return;
+    };
+    let [right] = comparators.as_slice() else {
+        return;
+    };
+
+    let left_arg = len_call_argument(checker, left);
+    let right_arg = len_call_argument(checker, right);
+
+    let kind = match (left_arg, right_arg) {
+        // `len(x) <op> len(y)`: only meaningful (to us) when `x` and `y` are the same
+        // expression, in which case the comparison's result doesn't depend on `x` at all.
+        (Some(left_arg), Some(right_arg)) => {
+            if ComparableExpr::from(left_arg) != ComparableExpr::from(right_arg) {
+                return;
+            }
+            classify_self_compare(*op)
+        }
+        (Some(_), None) => as_int_literal(right).and_then(|value| classify(*op, value)),
+        (None, Some(_)) => as_int_literal(left).and_then(|value| classify(flip(*op), value)),
+        (None, None) => return,
+    };
+
+    let Some(kind) = kind else {
+        return;
+    };
+
+    checker
+        .diagnostics
+        .push(Diagnostic::new(LenCompareToZero { kind }, *range));
+}
+
+/// If `expr` is a call to the builtin `len`, return its argument. Returns `None` (rather than
+/// the argument) if `len` has been shadowed.
+fn len_call_argument<'a>(checker: &Checker, expr: &'a Expr) -> Option<&'a Expr> {
+    let Expr::Call(ast::ExprCall { func, args, .. }) = expr else {
+        return None;
+    };
+    let Expr::Name(ast::ExprName { id, .. }) = func.as_ref() else {
+        return None;
+    };
+    if id != "len" || !checker.semantic().is_builtin(id) {
+        return None;
+    }
+    args.first()
+}
+
+/// Extract a plain integer literal (e.g. `0`, `-1`), rejecting anything else (floats, names,
+/// arithmetic expressions, etc.).
+fn as_int_literal(expr: &Expr) -> Option<i64> {
+    match expr {
+        Expr::Constant(ast::ExprConstant {
+            value: Constant::Int(value),
+            ..
+        }) => bigint_to_i64(value),
+        Expr::UnaryOp(ast::ExprUnaryOp {
+            op: ast::UnaryOp::USub,
+            operand,
+            ..
+        }) => as_int_literal(operand).map(|value| -value),
+        _ => None,
+    }
+}
+
+fn bigint_to_i64(value: &BigInt) -> Option<i64> {
+    use std::convert::TryFrom;
+    i64::try_from(value).ok()
+}
+
+fn flip(op: CmpOp) -> CmpOp {
+    match op {
+        CmpOp::Lt => CmpOp::Gt,
+        CmpOp::LtE => CmpOp::GtE,
+        CmpOp::Gt => CmpOp::Lt,
+        CmpOp::GtE => CmpOp::LtE,
+        other => other,
+    }
+}
+
+/// Classify a normalized `len(...) <op> value` comparison, given that `len()` can never be
+/// negative.
+fn classify(op: CmpOp, value: i64) -> Option<LenCompareKind> {
+    match (op, value) {
+        (CmpOp::Lt, value) if value <= 0 => Some(LenCompareKind::AlwaysFalse),
+        (CmpOp::LtE, value) if value < 0 => Some(LenCompareKind::AlwaysFalse),
+        (CmpOp::GtE, value) if value <= 0 => Some(LenCompareKind::AlwaysTrue),
+        (CmpOp::Gt, value) if value < 0 => Some(LenCompareKind::AlwaysTrue),
+        (CmpOp::LtE, 0) => Some(LenCompareKind::RedundantBound),
+        (CmpOp::Lt, 1) => Some(LenCompareKind::RedundantBound),
+        _ => None,
+    }
+}
+
+/// Classify `len(x) <op> len(x)`, where both sides are the same expression: the result depends
+/// only on `op`, never on `x`.
+fn classify_self_compare(op: CmpOp) -> Option<LenCompareKind> {
+    match op {
+        CmpOp::Lt | CmpOp::Gt | CmpOp::NotEq => Some(LenCompareKind::SelfCompareAlwaysFalse),
+        CmpOp::LtE | CmpOp::GtE | CmpOp::Eq => Some(LenCompareKind::SelfCompareAlwaysTrue),
+        CmpOp::Is | CmpOp::IsNot | CmpOp::In | CmpOp::NotIn => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{as_int_literal, classify, classify_self_compare, flip, LenCompareKind};
+    use rustpython_parser::ast::CmpOp;
+    use rustpython_parser::Parse;
+
+    fn parse_int_literal(source: &str) -> Option<i64> {
+        let expr = rustpython_parser::ast::Expr::parse(source, "<test>").unwrap();
+        as_int_literal(&expr)
+    }
+
+    #[test]
+    fn int_literal_accepts_plain_and_negative_integers() {
+        assert_eq!(parse_int_literal("0"), Some(0));
+        assert_eq!(parse_int_literal("1"), Some(1));
+        assert_eq!(parse_int_literal("-1"), Some(-1));
+    }
+
+    #[test]
+    fn int_literal_rejects_non_integers() {
+        assert_eq!(parse_int_literal("0.0"), None);
+        assert_eq!(parse_int_literal("x"), None);
+        assert_eq!(parse_int_literal("1 + 1"), None);
+    }
+
+    #[test]
+    fn flip_reverses_direction_but_not_equality() {
+        assert_eq!(flip(CmpOp::Lt), CmpOp::Gt);
+        assert_eq!(flip(CmpOp::LtE), CmpOp::GtE);
+        assert_eq!(flip(CmpOp::Gt), CmpOp::Lt);
+        assert_eq!(flip(CmpOp::GtE), CmpOp::LtE);
+        assert_eq!(flip(CmpOp::Eq), CmpOp::Eq);
+    }
+
+    #[test]
+    fn classify_flags_always_false_and_always_true() {
+        // len(x) < 0, len(x) <= -1
+        assert_eq!(classify(CmpOp::Lt, 0), Some(LenCompareKind::AlwaysFalse));
+        assert_eq!(classify(CmpOp::LtE, -1), Some(LenCompareKind::AlwaysFalse));
+        // len(x) >= 0, len(x) > -1
+        assert_eq!(classify(CmpOp::GtE, 0), Some(LenCompareKind::AlwaysTrue));
+        assert_eq!(classify(CmpOp::Gt, -1), Some(LenCompareKind::AlwaysTrue));
+    }
+
+    #[test]
+    fn classify_flags_redundant_bound() {
+        // len(x) <= 0, len(x) < 1
+        assert_eq!(classify(CmpOp::LtE, 0), Some(LenCompareKind::RedundantBound));
+        assert_eq!(classify(CmpOp::Lt, 1), Some(LenCompareKind::RedundantBound));
+    }
+
+    #[test]
+    fn classify_leaves_meaningful_comparisons_alone() {
+        // len(x) < 5, len(x) == 0, len(x) <= 1 are all meaningful as written.
+        assert_eq!(classify(CmpOp::Lt, 5), None);
+        assert_eq!(classify(CmpOp::Eq, 0), None);
+        assert_eq!(classify(CmpOp::LtE, 1), None);
+    }
+
+    #[test]
+    fn classify_self_compare_flags_always_false_and_always_true() {
+        // len(x) > len(x), len(x) != len(x)
+        assert_eq!(
+            classify_self_compare(CmpOp::Gt),
+            Some(LenCompareKind::SelfCompareAlwaysFalse)
+        );
+        assert_eq!(
+            classify_self_compare(CmpOp::NotEq),
+            Some(LenCompareKind::SelfCompareAlwaysFalse)
+        );
+        // len(x) >= len(x), len(x) == len(x)
+        assert_eq!(
+            classify_self_compare(CmpOp::GtE),
+            Some(LenCompareKind::SelfCompareAlwaysTrue)
+        );
+        assert_eq!(
+            classify_self_compare(CmpOp::Eq),
+            Some(LenCompareKind::SelfCompareAlwaysTrue)
+        );
+    }
+}
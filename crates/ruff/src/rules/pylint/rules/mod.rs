@@ -0,0 +1,9 @@
+pub(crate) use abstract_class_instantiated::*;
+pub(crate) use assert_on_string_literal::*;
+pub(crate) use len_compare::*;
+pub(crate) use using_constant_test::*;
+
+mod abstract_class_instantiated;
+mod assert_on_string_literal;
+mod len_compare;
+mod using_constant_test;
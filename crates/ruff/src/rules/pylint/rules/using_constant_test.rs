@@ -0,0 +1,149 @@
+use rustpython_parser::ast::{self, Constant, Expr, Ranged};
+
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+
+use crate::checkers::ast::Checker;
+use crate::rules::pylint::helpers::{constant_truthiness, Kind};
+
+#[derive(Debug, PartialEq, Eq)]
+enum DeadCode {
+    /// The branch can never be taken (e.g. `if False:`).
+    Branch,
+    /// The loop never terminates on its own, but isn't spelled the idiomatic way (e.g.
+    /// `while 1:` instead of `while True:`).
+    Loop,
+}
+
+/// ## What it does
+/// Checks for `if` and `while` statements whose condition is a constant that the interpreter
+/// can resolve without running the program.
+///
+/// Flags an `if` or `while` whose condition is always falsy, and a `while` whose condition is a
+/// truthy constant other than `True`. An `if` whose condition is always truthy, and
+/// `while True:`, are not flagged.
+///
+/// ## Why is this bad?
+/// A condition like `if False:` makes its branch statically dead code, which is usually a sign
+/// of a debugging leftover or a mistake, not intentional control flow. A condition like
+/// `while 1:` is a working idiom for an infinite loop, but `while True:` says the same thing
+/// more clearly and is what readers expect.
+///
+/// ## Example
+/// ```python
+/// if False:
+///     do_something()
+///
+/// while 1:
+///     do_something_else()
+/// ```
+///
+/// Use instead:
+/// ```python
+/// while True:
+///     do_something_else()
+/// ```
+#[violation]
+pub struct UsingConstantTest {
+    dead_code: DeadCode,
+}
+
+impl Violation for UsingConstantTest {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        match self.dead_code {
+            DeadCode::Branch => {
+                "Using a conditional statement with a constant value that is always false"
+                    .to_string()
+            }
+            DeadCode::Loop => {
+                "Using a constant value for a `while` loop condition; use `while True` instead"
+                    .to_string()
+            }
+        }
+    }
+}
+
+/// PLW0130
+pub(crate) fn using_constant_test(checker: &mut Checker, test: &Expr, is_while: bool) {
+    let Some(dead_code) = dead_code_kind(test, is_while) else {
+        return;
+    };
+
+    checker
+        .diagnostics
+        .push(Diagnostic::new(UsingConstantTest { dead_code }, test.range()));
+}
+
+/// Classify a `test` expression used as the condition of an `if` statement (`is_while: false`)
+/// or a `while` loop (`is_while: true`), or `None` if it isn't a constant condition worth
+/// flagging.
+fn dead_code_kind(test: &Expr, is_while: bool) -> Option<DeadCode> {
+    // `while True:` is the idiomatic spelling of an infinite loop; leave it alone.
+    if is_while && is_true_singleton(test) {
+        return None;
+    }
+
+    match constant_truthiness(test) {
+        Kind::Empty => Some(DeadCode::Branch),
+        Kind::NonEmpty if is_while => Some(DeadCode::Loop),
+        _ => None,
+    }
+}
+
+fn is_true_singleton(expr: &Expr) -> bool {
+    matches!(
+        expr,
+        Expr::Constant(ast::ExprConstant {
+            value: Constant::Bool(true),
+            ..
+        })
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use rustpython_parser::ast::Expr;
+    use rustpython_parser::Parse;
+
+    use super::{dead_code_kind, DeadCode};
+
+    fn classify(source: &str, is_while: bool) -> Option<DeadCode> {
+        let expr = Expr::parse(source, "<test>").unwrap();
+        dead_code_kind(&expr, is_while)
+    }
+
+    #[test]
+    fn while_true_is_left_alone() {
+        assert_eq!(classify("True", true), None);
+    }
+
+    #[test]
+    fn while_truthy_non_true_constant_is_flagged_as_loop() {
+        // `while 1:`, `while "x":`, etc. work, but aren't the idiomatic `while True:`.
+        assert_eq!(classify("1", true), Some(DeadCode::Loop));
+        assert_eq!(classify("\"x\"", true), Some(DeadCode::Loop));
+    }
+
+    #[test]
+    fn falsy_constant_is_flagged_as_dead_branch_for_if_and_while() {
+        assert_eq!(classify("False", false), Some(DeadCode::Branch));
+        assert_eq!(classify("0", false), Some(DeadCode::Branch));
+        assert_eq!(classify("False", true), Some(DeadCode::Branch));
+        assert_eq!(classify("0", true), Some(DeadCode::Branch));
+    }
+
+    #[test]
+    fn truthy_constant_if_condition_is_not_flagged() {
+        // `if 1:` is dead code in the sense that the branch always runs, but that's not what
+        // this rule is about -- there's no idiomatic alternative spelling to suggest.
+        assert_eq!(classify("1", false), None);
+        assert_eq!(classify("True", false), None);
+    }
+
+    #[test]
+    fn non_constant_condition_is_not_flagged() {
+        assert_eq!(classify("x", false), None);
+        assert_eq!(classify("x", true), None);
+    }
+}
@@ -0,0 +1,3 @@
+pub(crate) use unsorted_dunder_all::*;
+
+mod unsorted_dunder_all;
@@ -0,0 +1,84 @@
+use rustpython_parser::ast::{self, Expr, Ranged};
+
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+
+use crate::checkers::ast::Checker;
+use crate::rules::isort::sorting::Sorting;
+
+/// ## What it does
+/// Checks for `__all__` definitions that are not sorted.
+///
+/// ## Why is this bad?
+/// Consistently sorting `__all__` makes it easy to tell, at a glance, whether a name is
+/// exported, and avoids merge conflicts from appending to the end of an unsorted list.
+///
+/// By default, names are compared byte-for-byte (`item16` sorts before `item8`); set
+/// `isort.sorting` to `Sorting::Natural` to instead sort numbered names the way a reader would
+/// expect (`item8` before `item16`).
+///
+/// ## Example
+/// ```python
+/// __all__ = ["b", "a"]
+/// ```
+///
+/// Use instead:
+/// ```python
+/// __all__ = ["a", "b"]
+/// ```
+#[violation]
+pub struct UnsortedDunderAll;
+
+impl Violation for UnsortedDunderAll {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        "`__all__` is not sorted".to_string()
+    }
+}
+
+/// RUF022
+pub(crate) fn unsorted_dunder_all(checker: &mut Checker, target: &Expr, value: &Expr) {
+    let Expr::Name(ast::ExprName { id, .. }) = target else {
+        return;
+    };
+    if id != "__all__" {
+        return;
+    }
+
+    let Some(names) = string_elements(value) else {
+        return;
+    };
+
+    let sorting = checker.settings.isort.sorting;
+    let is_sorted = names
+        .windows(2)
+        .all(|pair| sorting.compare(pair[0], pair[1]).is_le());
+
+    if !is_sorted {
+        checker
+            .diagnostics
+            .push(Diagnostic::new(UnsortedDunderAll, value.range()));
+    }
+}
+
+/// Extract the string literal elements of a `list`/`tuple`/`set` display, bailing out (returning
+/// `None`) as soon as any element isn't a plain string literal — we can't judge the
+/// sortedness of a display that also contains a starred expression, a variable, etc.
+fn string_elements(expr: &Expr) -> Option<Vec<&str>> {
+    let elts = match expr {
+        Expr::List(ast::ExprList { elts, .. })
+        | Expr::Tuple(ast::ExprTuple { elts, .. })
+        | Expr::Set(ast::ExprSet { elts, .. }) => elts,
+        _ => return None,
+    };
+
+    elts.iter()
+        .map(|elt| match elt {
+            Expr::Constant(ast::ExprConstant {
+                value: ast::Constant::Str(value),
+                ..
+            }) => Some(value.as_str()),
+            _ => None,
+        })
+        .collect()
+}